@@ -4,7 +4,7 @@ use std::{
 };
 
 use regex::Regex;
-use time::{OffsetDateTime, error::ComponentRange, format_description::well_known};
+use time::{error::ComponentRange, format_description::well_known, OffsetDateTime, UtcOffset};
 
 pub trait ParseSystemTime {
     fn parse_system_time(&self) -> Result<SystemTime, TimeFormatError>;
@@ -14,7 +14,20 @@ pub trait ParseSystemTime {
 pub enum TimeFormat<'a> {
     UnixTimestamp(&'a str),
     Rfc3339(&'a str),
-    DateOnly(&'a str),
+    Rfc2822(&'a str),
+    DateOnly {
+        input: &'a str,
+        offset: UtcOffset,
+    },
+    NaiveDateTime {
+        input: &'a str,
+        offset: UtcOffset,
+    },
+    Custom {
+        input: &'a str,
+        pattern: &'a str,
+        offset: UtcOffset,
+    },
     InvalidFormat,
 }
 
@@ -37,18 +50,32 @@ impl From<ComponentRange> for TimeFormatError {
 
 impl<'a> TimeFormat<'a> {
     pub fn new(s: &'a str) -> Self {
+        Self::new_with_offset(s, UtcOffset::UTC)
+    }
+
+    pub fn new_with_offset(s: &'a str, offset: UtcOffset) -> Self {
         let unix_timestamp_regex = Regex::new(r"^\d{10,13}$").expect("Invalid Timestamp Regex");
         let rfc3339_regex =
-            Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:\d{2})$")
+            Regex::new(r"^\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}(\.\d+)?(Z|[+-]\d{2}:\d{2})$")
                 .expect("Invalid RFC 3339 Regex");
+        let rfc2822_regex = Regex::new(
+            r"^[A-Za-z]{3}, \d{2} [A-Za-z]{3} \d{4} \d{2}:\d{2}:\d{2} ([+-]\d{4}|[A-Za-z]{1,5})$",
+        )
+        .expect("Invalid RFC 2822 Regex");
+        let naive_datetime_regex = Regex::new(r"^\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}:\d{2}$")
+            .expect("Invalid Naive Datetime Regex");
         let date_only_regex = Regex::new(r"^\d+-\d+-\d+$").expect("Invalid Date Only Regex");
 
         if unix_timestamp_regex.is_match(s) {
             Self::UnixTimestamp(s)
         } else if rfc3339_regex.is_match(s) {
             Self::Rfc3339(s)
+        } else if rfc2822_regex.is_match(s) {
+            Self::Rfc2822(s)
+        } else if naive_datetime_regex.is_match(s) {
+            Self::NaiveDateTime { input: s, offset }
         } else if date_only_regex.is_match(s) {
-            Self::DateOnly(s)
+            Self::DateOnly { input: s, offset }
         } else {
             Self::InvalidFormat
         }
@@ -85,13 +112,21 @@ impl<'a> ParseSystemTime for TimeFormat<'a> {
         }
 
         fn parse_rfc3339(s: &str) -> Result<SystemTime, TimeFormatError> {
-            let dt = OffsetDateTime::parse(s, &well_known::Rfc3339)
+            let normalized = s.replacen(' ', "T", 1);
+            let dt = OffsetDateTime::parse(&normalized, &well_known::Rfc3339)
+                .map_err(|_| TimeFormatError::InvalidFormat)?;
+
+            parse_offset_date_time(dt)
+        }
+
+        fn parse_rfc2822(s: &str) -> Result<SystemTime, TimeFormatError> {
+            let dt = OffsetDateTime::parse(s, &well_known::Rfc2822)
                 .map_err(|_| TimeFormatError::InvalidFormat)?;
 
             parse_offset_date_time(dt)
         }
 
-        fn parse_date_only(s: &str) -> Result<SystemTime, TimeFormatError> {
+        fn parse_date_only(s: &str, offset: UtcOffset) -> Result<SystemTime, TimeFormatError> {
             let mut parts = s.split('-');
 
             let year: i32 = parts
@@ -114,23 +149,96 @@ impl<'a> ParseSystemTime for TimeFormat<'a> {
             let month = time::Month::try_from(month)?;
             let date = time::Date::from_calendar_date(year, month, day)?;
 
-            let rfc3339 = date
+            let date_time = date
                 .with_hms(0, 0, 0)
                 .map_err(|_| TimeFormatError::InvalidFormat)?
-                .assume_utc();
+                .assume_offset(offset);
+
+            parse_offset_date_time(date_time)
+        }
+
+        fn parse_naive_datetime(s: &str, offset: UtcOffset) -> Result<SystemTime, TimeFormatError> {
+            let normalized = s.replacen(' ', "T", 1);
+            let format = time::format_description::parse_strftime_borrowed("%Y-%m-%dT%H:%M:%S")
+                .map_err(|_| TimeFormatError::InvalidFormat)?;
+            let date_time = time::PrimitiveDateTime::parse(&normalized, &format)
+                .map_err(|_| TimeFormatError::InvalidFormat)?;
+
+            parse_offset_date_time(date_time.assume_offset(offset))
+        }
+
+        fn parse_custom(
+            input: &str,
+            pattern: &str,
+            offset: UtcOffset,
+        ) -> Result<SystemTime, TimeFormatError> {
+            let format = time::format_description::parse_strftime_borrowed(pattern)
+                .map_err(|_| TimeFormatError::InvalidFormat)?;
+            let date_time = time::PrimitiveDateTime::parse(input, &format)
+                .map_err(|_| TimeFormatError::InvalidFormat)?;
 
-            parse_offset_date_time(rfc3339)
+            parse_offset_date_time(date_time.assume_offset(offset))
         }
 
         match self {
             TimeFormat::UnixTimestamp(s) => parse_unix_timestamp(s),
             TimeFormat::Rfc3339(s) => parse_rfc3339(s),
-            TimeFormat::DateOnly(s) => parse_date_only(s),
+            TimeFormat::Rfc2822(s) => parse_rfc2822(s),
+            TimeFormat::DateOnly { input, offset } => parse_date_only(input, *offset),
+            TimeFormat::NaiveDateTime { input, offset } => parse_naive_datetime(input, *offset),
+            TimeFormat::Custom {
+                input,
+                pattern,
+                offset,
+            } => parse_custom(input, pattern, *offset),
             TimeFormat::InvalidFormat => Err(TimeFormatError::InvalidFormat),
         }
     }
 }
 
+pub trait FormatSystemTime {
+    fn format_system_time(&self, time: SystemTime) -> Result<String, TimeFormatError>;
+}
+
+#[derive(Debug, PartialEq)]
+pub enum OutputFormat<'a> {
+    Rfc3339,
+    Unix,
+    Custom(&'a str),
+}
+
+impl<'a> FormatSystemTime for OutputFormat<'a> {
+    fn format_system_time(&self, time: SystemTime) -> Result<String, TimeFormatError> {
+        fn to_offset_date_time(duration: Duration) -> Result<OffsetDateTime, TimeFormatError> {
+            let time_duration =
+                time::Duration::try_from(duration).map_err(|_| TimeFormatError::InvalidFormat)?;
+
+            OffsetDateTime::UNIX_EPOCH
+                .checked_add(time_duration)
+                .ok_or(TimeFormatError::InvalidFormat)
+        }
+
+        let duration = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(|_| TimeFormatError::InvalidFormat)?;
+
+        match self {
+            OutputFormat::Unix => Ok(duration.as_millis().to_string()),
+            OutputFormat::Rfc3339 => to_offset_date_time(duration)?
+                .format(&well_known::Rfc3339)
+                .map_err(|_| TimeFormatError::InvalidFormat),
+            OutputFormat::Custom(pattern) => {
+                let format = time::format_description::parse_strftime_borrowed(pattern)
+                    .map_err(|_| TimeFormatError::InvalidFormat)?;
+
+                to_offset_date_time(duration)?
+                    .format(&format)
+                    .map_err(|_| TimeFormatError::InvalidFormat)
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -138,7 +246,27 @@ mod tests {
     use super::*;
 
     #[rstest]
-    #[case("2026-01-01", TimeFormat::DateOnly("2026-01-01"))]
+    #[case(
+        "2026-01-01",
+        TimeFormat::DateOnly {
+            input: "2026-01-01",
+            offset: UtcOffset::UTC,
+        }
+    )]
+    #[case(
+        "2026-01-01T12:34:56",
+        TimeFormat::NaiveDateTime {
+            input: "2026-01-01T12:34:56",
+            offset: UtcOffset::UTC,
+        }
+    )]
+    #[case(
+        "2026-01-01 12:34:56",
+        TimeFormat::NaiveDateTime {
+            input: "2026-01-01 12:34:56",
+            offset: UtcOffset::UTC,
+        }
+    )]
     #[case(
         "2026-01-01T12:00:00+08:00",
         TimeFormat::Rfc3339("2026-01-01T12:00:00+08:00")
@@ -160,6 +288,27 @@ mod tests {
         "2026-01-01T12:00:00.123Z",
         TimeFormat::Rfc3339("2026-01-01T12:00:00.123Z")
     )]
+    #[case("2026-01-01 12:00:00Z", TimeFormat::Rfc3339("2026-01-01 12:00:00Z"))]
+    #[case(
+        "2026-01-01 12:34:56+00:00",
+        TimeFormat::Rfc3339("2026-01-01 12:34:56+00:00")
+    )]
+    #[case(
+        "2026-01-01 12:00:00.123-03:00",
+        TimeFormat::Rfc3339("2026-01-01 12:00:00.123-03:00")
+    )]
+    #[case(
+        "Thu, 01 Jan 2026 12:34:56 +0000",
+        TimeFormat::Rfc2822("Thu, 01 Jan 2026 12:34:56 +0000")
+    )]
+    #[case(
+        "Thu, 01 Jan 2026 12:34:56 -0300",
+        TimeFormat::Rfc2822("Thu, 01 Jan 2026 12:34:56 -0300")
+    )]
+    #[case(
+        "Thu, 01 Jan 2026 12:34:56 GMT",
+        TimeFormat::Rfc2822("Thu, 01 Jan 2026 12:34:56 GMT")
+    )]
     #[case("1767296965", TimeFormat::UnixTimestamp("1767296965"))]
     #[case("17672969655", TimeFormat::UnixTimestamp("17672969655"))]
     #[case("176729696559", TimeFormat::UnixTimestamp("176729696559"))]
@@ -191,17 +340,64 @@ mod tests {
     }
 
     #[rstest]
-    #[case(TimeFormat::DateOnly("2026-01-01"), 1767225600000)]
+    #[case(
+        TimeFormat::DateOnly {
+            input: "2026-01-01",
+            offset: UtcOffset::UTC,
+        },
+        1767225600000
+    )]
+    #[case(
+        TimeFormat::DateOnly {
+            input: "2026-01-01",
+            offset: UtcOffset::from_hms(-3, 0, 0).expect("Must be a valid offset."),
+        },
+        1767236400000
+    )]
+    #[case(
+        TimeFormat::NaiveDateTime {
+            input: "2026-01-01T12:34:56",
+            offset: UtcOffset::UTC,
+        },
+        1767270896000
+    )]
+    #[case(
+        TimeFormat::NaiveDateTime {
+            input: "2026-01-01 12:34:56",
+            offset: UtcOffset::from_hms(-3, 0, 0).expect("Must be a valid offset."),
+        },
+        1767281696000
+    )]
     #[case(TimeFormat::Rfc3339("2026-01-01T12:34:56+08:00"), 1767242096000)]
     #[case(TimeFormat::Rfc3339("2026-01-01T12:34:56.789+08:00"), 1767242096789)]
     #[case(TimeFormat::Rfc3339("2026-01-01T12:34:56-03:00"), 1767281696000)]
     #[case(TimeFormat::Rfc3339("2026-01-01T12:34:56.789-03:00"), 1767281696789)]
     #[case(TimeFormat::Rfc3339("2026-01-01T12:34:56Z"), 1767270896000)]
     #[case(TimeFormat::Rfc3339("2026-01-01T12:34:56.789Z"), 1767270896789)]
+    #[case(TimeFormat::Rfc3339("2026-01-01 12:34:56Z"), 1767270896000)]
+    #[case(TimeFormat::Rfc3339("2026-01-01 12:34:56+00:00"), 1767270896000)]
+    #[case(TimeFormat::Rfc2822("Thu, 01 Jan 2026 12:34:56 +0000"), 1767270896000)]
+    #[case(TimeFormat::Rfc2822("Thu, 01 Jan 2026 12:34:56 -0300"), 1767281696000)]
     #[case(TimeFormat::UnixTimestamp("1767296965"), 1767296965000)]
     #[case(TimeFormat::UnixTimestamp("17672969655"), 17672969655)]
     #[case(TimeFormat::UnixTimestamp("176729696559"), 176729696559)]
     #[case(TimeFormat::UnixTimestamp("1767296965592"), 1767296965592)]
+    #[case(
+        TimeFormat::Custom {
+            input: "01/02/2026 13:45",
+            pattern: "%m/%d/%Y %H:%M",
+            offset: UtcOffset::UTC,
+        },
+        1767361500000
+    )]
+    #[case(
+        TimeFormat::Custom {
+            input: "01/02/2026 13:45",
+            pattern: "%m/%d/%Y %H:%M",
+            offset: UtcOffset::from_hms(-3, 0, 0).expect("Must be valid UtcOffset at this point."),
+        },
+        1767372300000
+    )]
     fn should_parse_time_format_to_system_time(
         #[case] time_format: TimeFormat,
         #[case] expected_timestamp_millis: u128,
@@ -244,6 +440,40 @@ mod tests {
         TimeFormat::Rfc3339("2026-01-01T12:00:60Z"),
         TimeFormatError::InvalidFormat
     )]
+    #[case(
+        TimeFormat::Custom {
+            input: "01/02/2026 13:45",
+            pattern: "%Y-%m-%d",
+            offset: UtcOffset::UTC,
+        },
+        TimeFormatError::InvalidFormat
+    )]
+    #[case(
+        TimeFormat::Custom {
+            input: "01/02/2026 13:45",
+            pattern: "%Q",
+            offset: UtcOffset::UTC,
+        },
+        TimeFormatError::InvalidFormat
+    )]
+    #[case(
+        TimeFormat::Rfc2822("Thu, 32 Jan 2026 12:34:56 +0000"),
+        TimeFormatError::InvalidFormat
+    )]
+    #[case(
+        TimeFormat::NaiveDateTime {
+            input: "2026-01-32T12:34:56",
+            offset: UtcOffset::UTC,
+        },
+        TimeFormatError::InvalidFormat
+    )]
+    #[case(
+        TimeFormat::DateOnly {
+            input: "2026-13-01",
+            offset: UtcOffset::UTC,
+        },
+        TimeFormatError::InvalidFormat
+    )]
     fn should_error_when_parsing_non_valid_time_format(
         #[case] time_format: TimeFormat,
         #[case] expected_error: TimeFormatError,
@@ -258,4 +488,54 @@ mod tests {
         let error = system_time.expect_err("Must not be valid System Time at this point.");
         assert_eq!(error, expected_error);
     }
+
+    #[rstest]
+    #[case(OutputFormat::Rfc3339, "2026-01-01T12:34:56Z")]
+    #[case(OutputFormat::Unix, "1767270896000")]
+    #[case(OutputFormat::Custom("%Y-%m-%d %H:%M:%S"), "2026-01-01 12:34:56")]
+    fn should_format_system_time(#[case] output_format: OutputFormat, #[case] expected: &str) {
+        // Arrange
+        let system_time = SystemTime::UNIX_EPOCH + Duration::from_millis(1767270896000);
+
+        // Act
+        let formatted = output_format.format_system_time(system_time);
+
+        // Assert
+        assert!(formatted.is_ok());
+        assert_eq!(formatted.expect("Must be valid at this point."), expected);
+    }
+
+    #[rstest]
+    #[case(OutputFormat::Rfc3339)]
+    #[case(OutputFormat::Custom("%Y-%m-%d %H:%M:%S"))]
+    fn should_error_formatting_max_ulid_timestamp(#[case] output_format: OutputFormat) {
+        // Arrange
+        // Max ULID timestamp: 2^48 - 1 milliseconds since Unix Epoch, which
+        // is well past `OffsetDateTime`'s max representable year.
+        let system_time = SystemTime::UNIX_EPOCH + Duration::from_millis(281_474_976_710_655);
+
+        // Act
+        let formatted = output_format.format_system_time(system_time);
+
+        // Assert
+        assert!(formatted.is_err());
+        let error = formatted.expect_err("Must not be valid at this point.");
+        assert_eq!(error, TimeFormatError::InvalidFormat);
+    }
+
+    #[test]
+    fn should_format_max_ulid_timestamp_as_unix() {
+        // Arrange
+        let system_time = SystemTime::UNIX_EPOCH + Duration::from_millis(281_474_976_710_655);
+
+        // Act
+        let formatted = OutputFormat::Unix.format_system_time(system_time);
+
+        // Assert
+        assert!(formatted.is_ok());
+        assert_eq!(
+            formatted.expect("Must be valid at this point."),
+            "281474976710655"
+        );
+    }
 }
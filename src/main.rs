@@ -1,8 +1,10 @@
+use time::UtcOffset;
 use ulid::Ulid;
-use ulidgen::{ParseSystemTime, TimeFormat, TimeFormatError};
+use ulidgen::{FormatSystemTime, OutputFormat, ParseSystemTime, TimeFormat, TimeFormatError};
 
 const INVALID_ARGS_ERROR_MESSAGE: &str = "Invalid arguments.";
 const INVALID_TIME_ERROR_MESSAGE: &str = "Invalid time format.";
+const INVALID_ULID_ERROR_MESSAGE: &str = "Invalid ULID.";
 const HELP_MESSAGE: &str = r#"USAGE:
     ulidgen [OPTIONS]
 
@@ -17,10 +19,51 @@ OPTIONS:
 
         - RFC 3339
           - Timezone offsets are supported
+          - A space may be used instead of the `T` date/time separator
+
+        - RFC 2822
+          - Timezone offsets are supported
 
         - Date only
           - Format: YYYY-MM-DD
-          - Interpreted as midnight UTC
+          - Interpreted as midnight in the assumed offset (see --offset/--local)
+
+        - Naive datetime
+          - Format: YYYY-MM-DDTHH:MM:SS (a space may be used instead of `T`)
+          - Interpreted in the assumed offset (see --offset/--local)
+
+    --offset <+-HH:MM>
+        Assumed offset for Date only and Naive datetime TIME values, and
+        for -f/--format PATTERN values. Defaults to UTC. Requires
+        -t/--time; not valid with -d/--decode. Mutually exclusive with
+        --local.
+
+    --local
+        Use the machine's local offset instead of UTC for Date only and
+        Naive datetime TIME values, and for -f/--format PATTERN values.
+        Requires -t/--time; not valid with -d/--decode. Mutually
+        exclusive with --offset.
+
+    -f, --format <PATTERN>
+        With -t/--time, parse TIME using a custom strftime-style PATTERN
+        instead of auto-detecting the format. Assumes UTC unless
+        --offset/--local is given.
+
+        With -d/--decode, render the decoded instant using a custom
+        strftime-style PATTERN instead of the default RFC 3339 output.
+
+    -d, --decode <ULID>
+        Decode the instant embedded in ULID and print it. Defaults to
+        RFC 3339 output; pair with --rfc3339, --unix, or -f/--format to
+        pick the output format.
+
+    --rfc3339
+        With -d/--decode, render the decoded instant as RFC 3339. This
+        is the default.
+
+    --unix
+        With -d/--decode, render the decoded instant as a unix
+        timestamp in milliseconds.
 
     -h, --help
         Print help message
@@ -39,15 +82,37 @@ EXAMPLES:
     Generate ULID for RFC 3339
       ulidgen -t 2026-01-01T12:34:56Z
       ulidgen -t 2026-01-01T12:34:56.789-03:00
+      ulidgen -t "2026-01-01 12:34:56+00:00"
+
+    Generate ULID for RFC 2822
+      ulidgen -t "Thu, 01 Jan 2026 12:34:56 +0000"
+      ulidgen -t "Thu, 01 Jan 2026 12:34:56 -0300"
 
     Generate ULID for Date Only
       ulidgen -t 2026-01-01
+      ulidgen -t 2026-01-01 --offset -03:00
+      ulidgen -t 2026-01-01 --local
+
+    Generate ULID for a Naive datetime
+      ulidgen -t 2026-01-01T12:34:56
+      ulidgen -t "2026-01-01 12:34:56" --offset -03:00
+
+    Generate ULID for a custom format
+      ulidgen -t "01/02/2026 13:45" -f "%m/%d/%Y %H:%M"
+      ulidgen -t "01/02/2026 13:45" -f "%m/%d/%Y %H:%M" --offset -03:00
+
+    Decode the instant embedded in a ULID
+      ulidgen -d 01JFK8V3R50000000000000000
+      ulidgen -d 01JFK8V3R50000000000000000 --unix
+      ulidgen -d 01JFK8V3R50000000000000000 -f "%Y-%m-%d %H:%M:%S"
 "#;
 
 #[derive(Debug, PartialEq)]
+#[allow(clippy::enum_variant_names)]
 enum RunError {
     InvalidArgs,
     InvalidTimeFormat,
+    InvalidUlid,
 }
 
 impl From<TimeFormatError> for RunError {
@@ -56,22 +121,150 @@ impl From<TimeFormatError> for RunError {
     }
 }
 
+#[derive(Debug, Default, PartialEq)]
+struct ParsedArgs {
+    help: bool,
+    version: bool,
+    time: Option<String>,
+    format: Option<String>,
+    decode: Option<String>,
+    rfc3339: bool,
+    unix: bool,
+    offset: Option<String>,
+    local: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<ParsedArgs, RunError> {
+    let mut parsed = ParsedArgs::default();
+    let mut rest = args.iter().skip(1);
+
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "-v" | "--version" => parsed.version = true,
+            "-h" | "--help" => parsed.help = true,
+            "-t" | "--time" => {
+                parsed.time = Some(rest.next().ok_or(RunError::InvalidArgs)?.clone());
+            }
+            "-f" | "--format" => {
+                parsed.format = Some(rest.next().ok_or(RunError::InvalidArgs)?.clone());
+            }
+            "-d" | "--decode" => {
+                parsed.decode = Some(rest.next().ok_or(RunError::InvalidArgs)?.clone());
+            }
+            "--rfc3339" => parsed.rfc3339 = true,
+            "--unix" => parsed.unix = true,
+            "--offset" => {
+                parsed.offset = Some(rest.next().ok_or(RunError::InvalidArgs)?.clone());
+            }
+            "--local" => parsed.local = true,
+            _ => Err(RunError::InvalidArgs)?,
+        }
+    }
+
+    Ok(parsed)
+}
+
+fn parse_offset(s: &str) -> Result<UtcOffset, RunError> {
+    let (sign, rest) = if let Some(rest) = s.strip_prefix('+') {
+        (1, rest)
+    } else if let Some(rest) = s.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        return Err(RunError::InvalidArgs);
+    };
+
+    let mut parts = rest.split(':');
+    let hours: i8 = parts
+        .next()
+        .ok_or(RunError::InvalidArgs)?
+        .parse()
+        .map_err(|_| RunError::InvalidArgs)?;
+    let minutes: i8 = parts
+        .next()
+        .ok_or(RunError::InvalidArgs)?
+        .parse()
+        .map_err(|_| RunError::InvalidArgs)?;
+    parts
+        .next()
+        .map_or(Ok(()), |_| Err(RunError::InvalidArgs))?;
+
+    UtcOffset::from_hms(sign * hours, sign * minutes, 0).map_err(|_| RunError::InvalidArgs)
+}
+
+fn resolve_offset(offset: Option<&str>, local: bool) -> Result<UtcOffset, RunError> {
+    match (offset, local) {
+        (Some(s), false) => parse_offset(s),
+        (None, true) => UtcOffset::current_local_offset().map_err(|_| RunError::InvalidArgs),
+        (None, false) => Ok(UtcOffset::UTC),
+        (Some(_), true) => Err(RunError::InvalidArgs),
+    }
+}
+
+fn generate(
+    time: Option<String>,
+    format: Option<String>,
+    offset: UtcOffset,
+) -> Result<String, RunError> {
+    match (time, format) {
+        (Some(time), Some(pattern)) => Ok(Ulid::from_datetime(
+            TimeFormat::Custom {
+                input: &time,
+                pattern: &pattern,
+                offset,
+            }
+            .parse_system_time()?,
+        )
+        .to_string()),
+        (Some(time), None) => Ok(Ulid::from_datetime(
+            TimeFormat::new_with_offset(&time, offset).parse_system_time()?,
+        )
+        .to_string()),
+        (None, Some(_)) => Err(RunError::InvalidArgs),
+        (None, None) => Ok(Ulid::new().to_string()),
+    }
+}
+
+fn decode(ulid: &str, format: Option<&str>, rfc3339: bool, unix: bool) -> Result<String, RunError> {
+    let output_format = match (format, unix, rfc3339) {
+        (Some(pattern), _, _) => OutputFormat::Custom(pattern),
+        (None, true, _) => OutputFormat::Unix,
+        (None, false, _) => OutputFormat::Rfc3339,
+    };
+
+    let ulid = Ulid::from_string(ulid).map_err(|_| RunError::InvalidUlid)?;
+    let system_time =
+        std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(ulid.timestamp_ms());
+
+    Ok(output_format.format_system_time(system_time)?)
+}
+
 fn run(args: &[String]) -> Result<String, RunError> {
-    match args {
-        [_, opt] => match opt.as_str() {
-            "-v" | "--version" => Ok(env!("CARGO_PKG_VERSION").to_string()),
-            "-h" | "--help" => Ok(HELP_MESSAGE.to_string()),
-            _ => Err(RunError::InvalidArgs),
-        },
-        [_, opt, parameter] => match opt.as_str() {
-            "-t" | "--time" => Ok(Ulid::from_datetime(
-                TimeFormat::new(parameter).parse_system_time()?,
-            )
-            .to_string()),
-            _ => Err(RunError::InvalidArgs),
-        },
-        [_] => Ok(Ulid::new().to_string()),
-        _ => Err(RunError::InvalidArgs),
+    let parsed = parse_args(args)?;
+
+    if parsed.version {
+        return Ok(env!("CARGO_PKG_VERSION").to_string());
+    }
+
+    if parsed.help {
+        return Ok(HELP_MESSAGE.to_string());
+    }
+
+    match &parsed.decode {
+        Some(ulid) => {
+            if parsed.offset.is_some() || parsed.local {
+                return Err(RunError::InvalidArgs);
+            }
+
+            decode(ulid, parsed.format.as_deref(), parsed.rfc3339, parsed.unix)
+        }
+        None => {
+            if parsed.time.is_none() && (parsed.offset.is_some() || parsed.local) {
+                return Err(RunError::InvalidArgs);
+            }
+
+            let offset = resolve_offset(parsed.offset.as_deref(), parsed.local)?;
+            generate(parsed.time, parsed.format, offset)
+        }
     }
 }
 
@@ -91,6 +284,10 @@ fn main() {
             eprintln!("{}\n", INVALID_TIME_ERROR_MESSAGE);
             eprintln!("{}", HELP_MESSAGE)
         }
+        Err(RunError::InvalidUlid) => {
+            eprintln!("{}\n", INVALID_ULID_ERROR_MESSAGE);
+            eprintln!("{}", HELP_MESSAGE)
+        }
     }
 }
 
@@ -165,8 +362,16 @@ mod tests {
     #[case(vec!["ulidgen".to_string(), "--time".to_string(), "2026-01-01T12:34:56Z".to_string()], 1767270896000)]
     #[case(vec!["ulidgen".to_string(), "-t".to_string(), "2026-01-01T12:34:56.789-03:00".to_string()], 1767281696789)]
     #[case(vec!["ulidgen".to_string(), "--time".to_string(), "2026-01-01T12:34:56.789-03:00".to_string()], 1767281696789)]
+    #[case(vec!["ulidgen".to_string(), "-t".to_string(), "2026-01-01 12:34:56Z".to_string()], 1767270896000)]
+    #[case(vec!["ulidgen".to_string(), "--time".to_string(), "2026-01-01 12:34:56+00:00".to_string()], 1767270896000)]
     #[case(vec!["ulidgen".to_string(), "-t".to_string(), "2026-01-01".to_string()], 1767225600000)]
     #[case(vec!["ulidgen".to_string(), "--time".to_string(), "2026-01-01".to_string()], 1767225600000)]
+    #[case(vec!["ulidgen".to_string(), "-t".to_string(), "Thu, 01 Jan 2026 12:34:56 +0000".to_string()], 1767270896000)]
+    #[case(vec!["ulidgen".to_string(), "--time".to_string(), "Thu, 01 Jan 2026 12:34:56 -0300".to_string()], 1767281696000)]
+    #[case(vec!["ulidgen".to_string(), "-t".to_string(), "2026-01-01T12:34:56".to_string()], 1767270896000)]
+    #[case(vec!["ulidgen".to_string(), "--time".to_string(), "2026-01-01 12:34:56".to_string()], 1767270896000)]
+    #[case(vec!["ulidgen".to_string(), "-t".to_string(), "2026-01-01T12:34:56".to_string(), "--offset".to_string(), "-03:00".to_string()], 1767281696000)]
+    #[case(vec!["ulidgen".to_string(), "-t".to_string(), "2026-01-01".to_string(), "--offset".to_string(), "-03:00".to_string()], 1767236400000)]
     fn should_return_ulid_with_time(#[case] args: Vec<String>, #[case] expected_timestamp_ms: u64) {
         // Arrange
         let args = args.as_slice();
@@ -182,6 +387,122 @@ mod tests {
         assert_eq!(timestamp, expected_timestamp_ms);
     }
 
+    #[rstest]
+    #[case(vec![
+        "ulidgen".to_string(),
+        "-t".to_string(),
+        "01/02/2026 13:45".to_string(),
+        "-f".to_string(),
+        "%m/%d/%Y %H:%M".to_string(),
+    ], 1767361500000)]
+    #[case(vec![
+        "ulidgen".to_string(),
+        "--time".to_string(),
+        "01/02/2026 13:45".to_string(),
+        "--format".to_string(),
+        "%m/%d/%Y %H:%M".to_string(),
+    ], 1767361500000)]
+    #[case(vec![
+        "ulidgen".to_string(),
+        "-t".to_string(),
+        "01/02/2026 13:45".to_string(),
+        "-f".to_string(),
+        "%m/%d/%Y %H:%M".to_string(),
+        "--offset".to_string(),
+        "-03:00".to_string(),
+    ], 1767372300000)]
+    fn should_return_ulid_with_custom_format(
+        #[case] args: Vec<String>,
+        #[case] expected_timestamp_ms: u64,
+    ) {
+        // Arrange
+        let args = args.as_slice();
+
+        // Act
+        let result = run(args);
+
+        // Assert
+        assert!(result.is_ok());
+        let timestamp = Ulid::from_string(&result.expect("Must be valid at this point."))
+            .expect("Must be valid ULID at this point.")
+            .timestamp_ms();
+        assert_eq!(timestamp, expected_timestamp_ms);
+    }
+
+    #[test]
+    fn should_return_error_for_format_without_time() {
+        // Arrange
+        let args = vec![
+            "ulidgen".to_string(),
+            "-f".to_string(),
+            "%m/%d/%Y %H:%M".to_string(),
+        ];
+        let args = args.as_slice();
+
+        let expected_error = RunError::InvalidArgs;
+
+        // Act
+        let result = run(args);
+
+        // Assert
+        assert!(result.is_err());
+        let error = result.expect_err("Must not be valid at this point.");
+        assert_eq!(error, expected_error);
+    }
+
+    #[rstest]
+    #[case(vec![
+        "ulidgen".to_string(),
+        "-t".to_string(),
+        "2026-01-01".to_string(),
+        "--offset".to_string(),
+        "banana".to_string(),
+    ])]
+    #[case(vec![
+        "ulidgen".to_string(),
+        "-t".to_string(),
+        "2026-01-01".to_string(),
+        "--offset".to_string(),
+        "-03:00".to_string(),
+        "--local".to_string(),
+    ])]
+    #[case(vec!["ulidgen".to_string(), "--offset".to_string(), "-03:00".to_string()])]
+    #[case(vec!["ulidgen".to_string(), "--local".to_string()])]
+    #[case(vec![
+        "ulidgen".to_string(),
+        "-d".to_string(),
+        "01JFK8V3R5000000000000000".to_string(),
+        "--offset".to_string(),
+        "-03:00".to_string(),
+    ])]
+    #[case(vec![
+        "ulidgen".to_string(),
+        "-d".to_string(),
+        "01JFK8V3R5000000000000000".to_string(),
+        "--local".to_string(),
+    ])]
+    #[case(vec![
+        "ulidgen".to_string(),
+        "-d".to_string(),
+        "01JFK8V3R5000000000000000".to_string(),
+        "--offset".to_string(),
+        "banana".to_string(),
+    ])]
+    fn should_return_error_for_invalid_offset(#[case] args: Vec<String>) {
+        // Arrange
+        let args = args.as_slice();
+
+        let expected_error = RunError::InvalidArgs;
+
+        // Act
+        let result = run(args);
+
+        // Assert
+        assert!(result.is_err());
+        let error = result.expect_err("Must not be valid at this point.");
+        assert_eq!(error, expected_error);
+    }
+
     #[test]
     fn should_return_invalid_time_format() {
         // Arrange
@@ -217,4 +538,58 @@ mod tests {
         let ulid = Ulid::from_string(&result.expect("Must be valid at this point."));
         assert!(ulid.is_ok());
     }
+
+    fn ulid_with_timestamp_ms(timestamp_ms: u64) -> String {
+        Ulid::from_datetime(
+            std::time::SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(timestamp_ms),
+        )
+        .to_string()
+    }
+
+    #[rstest]
+    #[case(vec!["-d".to_string()], "2026-01-01T12:34:56Z")]
+    #[case(vec!["--decode".to_string()], "2026-01-01T12:34:56Z")]
+    #[case(vec!["-d".to_string(), "--rfc3339".to_string()], "2026-01-01T12:34:56Z")]
+    #[case(vec!["-d".to_string(), "--unix".to_string()], "1767270896000")]
+    #[case(
+        vec!["-d".to_string(), "-f".to_string(), "%Y-%m-%d %H:%M:%S".to_string()],
+        "2026-01-01 12:34:56"
+    )]
+    fn should_return_decoded_instant(#[case] flags: Vec<String>, #[case] expected: &str) {
+        // Arrange
+        let mut args = vec!["ulidgen".to_string()];
+        let mut flags = flags.into_iter();
+        args.push(flags.next().expect("Test case must pass a decode flag."));
+        args.push(ulid_with_timestamp_ms(1767270896000));
+        args.extend(flags);
+        let args = args.as_slice();
+
+        // Act
+        let result = run(args);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(result.expect("Must be valid at this point."), expected);
+    }
+
+    #[test]
+    fn should_return_invalid_ulid() {
+        // Arrange
+        let args = vec![
+            "ulidgen".to_string(),
+            "-d".to_string(),
+            "not-a-ulid".to_string(),
+        ];
+        let args = args.as_slice();
+
+        let expected_error = RunError::InvalidUlid;
+
+        // Act
+        let result = run(args);
+
+        // Assert
+        assert!(result.is_err());
+        let error = result.expect_err("Must not be valid at this point.");
+        assert_eq!(error, expected_error);
+    }
 }